@@ -1,8 +1,13 @@
-use std::{io::{BufWriter, Write}, path::PathBuf, time::Duration, ops::Deref};
+use std::{
+    collections::HashMap, io::{BufWriter, Write}, ops::Deref, path::PathBuf, sync::Arc,
+    time::Duration,
+};
 
+use async_trait::async_trait;
 use minidump::*;
 use minidump_processor::{
-    http_symbol_supplier, simple_symbol_supplier, MultiSymbolProvider, Symbolizer,
+    http_symbol_supplier, simple_symbol_supplier, FillSymbolError, FrameSymbolizer, FrameWalker,
+    MultiSymbolProvider, SymbolProvider, SymbolStats, Symbolizer,
 };
 use neon::prelude::*;
 use once_cell::sync::OnceCell;
@@ -16,12 +21,146 @@ fn runtime<'a, C: Context<'a>>(cx: &mut C) -> NeonResult<&'static Runtime> {
     RUNTIME.get_or_try_init(|| Runtime::new().or_else(|err| cx.throw_error(err.to_string())))
 }
 
+// Parse a JSON string into a JS value by round-tripping it through the
+// JS engine's own `JSON.parse`, rather than hand-rolling a serde_json::Value
+// -> JsObject walk.
+fn json_parse<'a, C: Context<'a>>(cx: &mut C, json: &str) -> JsResult<'a, JsValue> {
+    let global = cx.global_object();
+    let json_global: Handle<JsObject> = global.get(cx, "JSON")?;
+    let parse: Handle<JsFunction> = json_global.get(cx, "parse")?;
+    let json_str = cx.string(json);
+    let undefined = cx.undefined();
+    parse.call(cx, undefined, [json_str.upcast()])
+}
+
+// Throw an error with a machine-readable `kind` (the underlying Rust error's
+// `.name()`, or a fixed name such as "Aborted" or "Io") and `phase` (`"read"` or `"process"`) attached, so JS callers
+// can branch on `err.kind` instead of string-matching the message.
+fn throw_typed_error<'a, C: Context<'a>, T>(
+    cx: &mut C,
+    phase: &str,
+    kind: &str,
+    message: String,
+) -> NeonResult<T> {
+    let err = cx.error(message)?;
+    let kind = cx.string(kind);
+    err.set(cx, "kind", kind)?;
+    let phase = cx.string(phase);
+    err.set(cx, "phase", phase)?;
+    cx.throw(err)
+}
+
+// A minidump can come from a path on disk (`Minidump::read_path`, which
+// memory-maps the file) or from an in-memory Buffer/ArrayBuffer handed over
+// from JS, e.g. a dump streamed in over HTTP that should never touch disk.
+enum DumpSource {
+    Path(PathBuf),
+    Bytes(Vec<u8>),
+}
+
+impl DumpSource {
+    fn from_arg<'a, C: Context<'a>>(cx: &mut C, arg: Handle<'a, JsValue>) -> NeonResult<Self> {
+        if let Ok(path) = arg.downcast::<JsString, _>(cx) {
+            Ok(DumpSource::Path(PathBuf::from(path.value(cx))))
+        } else if let Ok(buf) = arg.downcast::<JsBuffer, _>(cx) {
+            Ok(DumpSource::Bytes(buf.as_slice(cx).to_vec()))
+        } else if let Ok(buf) = arg.downcast::<JsArrayBuffer, _>(cx) {
+            Ok(DumpSource::Bytes(buf.as_slice(cx).to_vec()))
+        } else if let Ok(buf) = arg.downcast::<JsTypedArray<u8>, _>(cx) {
+            Ok(DumpSource::Bytes(buf.as_slice(cx).to_vec()))
+        } else {
+            cx.throw_type_error("expected a minidump file path, or a Buffer/ArrayBuffer/Uint8Array")
+        }
+    }
+}
+
+// Dispatch a `DumpSource` to `Minidump::read_path`/`Minidump::read`, binding
+// the parsed dump to `$dump` for `$on_ok` or the read error to `$err` for
+// `$on_err`. A macro rather than a generic function because `read_path` and
+// `read` return different concrete `Minidump<'static, T>` instantiations
+// (`Mmap` vs `Vec<u8>`) that can't be unified under one return type.
+macro_rules! with_opened_dump {
+    ($source:expr, |$dump:ident| $on_ok:expr, |$err:ident| $on_err:expr) => {
+        match $source {
+            DumpSource::Path(path) => match Minidump::read_path(path) {
+                Ok($dump) => $on_ok,
+                Err($err) => $on_err,
+            },
+            DumpSource::Bytes(bytes) => match Minidump::read(bytes) {
+                Ok($dump) => $on_ok,
+                Err($err) => $on_err,
+            },
+        }
+    };
+}
+
+// Wraps a `SymbolProvider` so every module it symbolizes is also reported to
+// a JS `onProgress` callback, invoked through the Neon `Channel`.
+struct ProgressSymbolProvider<P> {
+    inner: P,
+    channel: Channel,
+    on_progress: Arc<Root<JsFunction>>,
+}
+
+#[async_trait]
+impl<P: SymbolProvider + Sync> SymbolProvider for ProgressSymbolProvider<P> {
+    async fn fill_symbol(
+        &self,
+        module: &dyn Module,
+        frame: &mut dyn FrameSymbolizer,
+    ) -> Result<(), FillSymbolError> {
+        let res = self.inner.fill_symbol(module, frame).await;
+        let module_name = module.code_file().into_owned();
+        let on_progress = self.on_progress.clone();
+        self.channel.send(move |mut cx| {
+            let callback = on_progress.to_inner(&mut cx);
+            let this = cx.undefined();
+            let name = cx.string(module_name);
+            callback.call(&mut cx, this, [name.upcast()])?;
+            Ok(())
+        });
+        res
+    }
+
+    fn stats(&self) -> HashMap<String, SymbolStats> {
+        self.inner.stats()
+    }
+
+    async fn walk_frame(&self, module: &dyn Module, walker: &mut dyn FrameWalker) -> Option<()> {
+        self.inner.walk_frame(module, walker).await
+    }
+}
+
+// The outcome of racing a stackwalk against an abort signal.
+enum StackwalkError {
+    Aborted,
+    Process(minidump_processor::ProcessError),
+}
+
+async fn run_stackwalk<T>(
+    dump: &Minidump<'static, T>,
+    provider: &MultiSymbolProvider,
+    abort_rx: Option<tokio::sync::oneshot::Receiver<()>>,
+) -> Result<minidump_processor::ProcessState, StackwalkError>
+where
+    T: Deref<Target = [u8]> + Sync,
+{
+    let process = minidump_processor::process_minidump(dump, provider);
+    match abort_rx {
+        Some(abort_rx) => tokio::select! {
+            res = process => res.map_err(StackwalkError::Process),
+            _ = abort_rx => Err(StackwalkError::Aborted),
+        },
+        None => process.await.map_err(StackwalkError::Process),
+    }
+}
+
 fn minidump_stackwalk(mut cx: FunctionContext) -> JsResult<JsPromise> {
     let rt = runtime(&mut cx)?;
     let channel = cx.channel();
 
-    let minidump_path: Handle<JsString> = cx.argument(0)?;
-    let minidump_path = PathBuf::from(minidump_path.value(&mut cx));
+    let source_arg: Handle<JsValue> = cx.argument(0)?;
+    let source = DumpSource::from_arg(&mut cx, source_arg)?;
     let opts: Option<Handle<JsValue>> = cx.argument_opt(1);
     let opts = match opts {
         Some(o) => o.downcast_or_throw::<JsObject, FunctionContext>(&mut cx)?,
@@ -64,75 +203,284 @@ fn minidump_stackwalk(mut cx: FunctionContext) -> JsResult<JsPromise> {
 
     let timeout = Duration::from_secs_f64(timeout.unwrap_or(1000.0));
 
+    let output: Option<Handle<JsString>> = opts.get_opt(&mut cx, "output")?;
+    let output = output.map(|x| x.value(&mut cx)).unwrap_or_else(|| "human".to_string());
+
+    let pretty: Option<Handle<JsBoolean>> = opts.get_opt(&mut cx, "pretty")?;
+    let pretty = pretty.map(|x| x.value(&mut cx)).unwrap_or(false);
+
+    let on_progress: Option<Handle<JsFunction>> = opts.get_opt(&mut cx, "onProgress")?;
+    let on_progress = on_progress.map(|f| Arc::new(f.root(&mut cx)));
+
+    let signal: Option<Handle<JsObject>> = opts.get_opt(&mut cx, "signal")?;
+    let abort_rx = signal.map(|s| register_abort_signal(&mut cx, s)).transpose()?;
+
     // Create a JavaScript promise and a `deferred` handle for resolving it.
     // It is important to be careful not to perform failable actions after
     // creating the promise to avoid an unhandled rejection.
     let (deferred, promise) = cx.promise();
 
     rt.spawn(async move {
-        match Minidump::read_path(minidump_path) {
-            Ok(dump) => {
-                let mut provider = MultiSymbolProvider::new();
-                if !symbol_urls_strs.is_empty() {
-                    provider.add(Box::new(Symbolizer::new(http_symbol_supplier(
-                        symbol_paths_strs,
-                        symbol_urls_strs,
-                        symbols_cache,
-                        symbols_tmp,
-                        timeout,
-                    ))));
-                } else if !symbol_paths_strs.is_empty() {
-                    provider.add(Box::new(Symbolizer::new(simple_symbol_supplier(
-                        symbol_paths_strs,
-                    ))));
-                }
+        let mut provider = MultiSymbolProvider::new();
+        if !symbol_urls_strs.is_empty() {
+            let symbolizer = Symbolizer::new(http_symbol_supplier(
+                symbol_paths_strs,
+                symbol_urls_strs,
+                symbols_cache,
+                symbols_tmp,
+                timeout,
+            ));
+            add_symbolizer(&mut provider, symbolizer, &channel, &on_progress);
+        } else if !symbol_paths_strs.is_empty() {
+            let symbolizer = Symbolizer::new(simple_symbol_supplier(symbol_paths_strs));
+            add_symbolizer(&mut provider, symbolizer, &channel, &on_progress);
+        }
 
-                let res = minidump_processor::process_minidump(&dump, &provider).await;
-                deferred.settle_with(&channel, move |mut cx| {
-                    match res {
-                        Ok(state) => {
-                            let mut buf = BufWriter::new(Vec::new());
-
-                            state.print(&mut buf).unwrap();
-                            
-                            // TODO: optionally return JSON?
-                            //state.print_json(&mut buf, false).unwrap();
-
-                            let bytes = buf.into_inner().unwrap();
-                            let string = String::from_utf8(bytes).unwrap();
-                            Ok(cx.string(string))
-                        }
-                        Err(err) => cx.throw_error(format!(
-                            "{} - Error processing dump: {}",
-                            err.name(),
-                            err
-                        )),
-                    }
-                })
+        with_opened_dump!(
+            source,
+            |dump| {
+                let module_debug_info = module_debug_info(&dump);
+                let res = run_stackwalk(&dump, &provider, abort_rx).await;
+                let symbol_stats = provider.stats();
+                drop(provider);
+                settle_stackwalk(
+                    deferred,
+                    channel,
+                    res,
+                    output,
+                    pretty,
+                    symbol_stats,
+                    module_debug_info,
+                    on_progress,
+                );
+            },
+            |err| {
+                drop(provider);
+                emit_read_error(deferred, channel, err, on_progress);
             }
-            Err(err) => deferred.settle_with(&channel, move |mut cx| {
-                let x: NeonResult<Handle<JsValue>> =
-                    cx.throw_error(format!("{} - Error reading dump: {}", err.name(), err));
-                x
-            }),
-        };
+        );
     });
 
     Ok(promise)
 }
 
+// Reject `deferred` with a typed "read" error, releasing the
+// `onProgress` callback's `Root` first when the caller passed one. Shared by
+// every exported function's `with_opened_dump!` error arm.
+fn emit_read_error(
+    deferred: Deferred,
+    channel: Channel,
+    err: Error,
+    on_progress: Option<Arc<Root<JsFunction>>>,
+) {
+    deferred.settle_with(&channel, move |mut cx| {
+        release_progress_callback(&mut cx, on_progress);
+        throw_typed_error(
+            &mut cx,
+            "read",
+            err.name(),
+            format!("Error reading dump: {}", err),
+        )
+    });
+}
+
+// Release the `onProgress` callback's persistent V8 handle. `provider` (the
+// only other holder of a clone, via `ProgressSymbolProvider`) must already
+// be dropped by the time this runs, so the `Arc` unwraps deterministically
+// instead of just leaking the `Root` when the last clone falls out of scope.
+fn release_progress_callback<'a, C: Context<'a>>(
+    cx: &mut C,
+    on_progress: Option<Arc<Root<JsFunction>>>,
+) {
+    if let Some(on_progress) = on_progress {
+        if let Ok(root) = Arc::try_unwrap(on_progress) {
+            root.drop(cx);
+        }
+    }
+}
+
+// Build a `code_file -> (debug_file, debug_id)` map from the dump's module
+// list, so symbol coverage stats (keyed by module name) can be joined back
+// to the identifiers symbol servers actually look modules up by.
+fn module_debug_info<'a, T>(dump: &Minidump<'a, T>) -> HashMap<String, (String, String)>
+where
+    T: Deref<Target = [u8]> + 'a,
+{
+    dump.get_stream::<MinidumpModuleList>()
+        .map(|list| {
+            list.iter()
+                .map(|m| {
+                    let debug_file = m.debug_file().map(|f| f.into_owned()).unwrap_or_default();
+                    let debug_id = m
+                        .debug_identifier()
+                        .map(|id| id.breakpad().to_string())
+                        .unwrap_or_default();
+                    (m.code_file().into_owned(), (debug_file, debug_id))
+                })
+                .collect()
+        })
+        .unwrap_or_default()
+}
+
+// Wrap `symbolizer` in a `ProgressSymbolProvider` when the caller passed an
+// `onProgress` callback, then add it to `provider`.
+fn add_symbolizer(
+    provider: &mut MultiSymbolProvider,
+    symbolizer: Symbolizer,
+    channel: &Channel,
+    on_progress: &Option<Arc<Root<JsFunction>>>,
+) {
+    match on_progress {
+        Some(on_progress) => provider.add(Box::new(ProgressSymbolProvider {
+            inner: symbolizer,
+            channel: channel.clone(),
+            on_progress: on_progress.clone(),
+        })),
+        None => provider.add(Box::new(symbolizer)),
+    }
+}
+
+// Subscribe to a JS AbortSignal-like object's `abort` event and return a
+// receiver that resolves as soon as it fires (or immediately if the signal
+// was already aborted before we got here).
+fn register_abort_signal<'a, C: Context<'a>>(
+    cx: &mut C,
+    signal: Handle<'a, JsObject>,
+) -> NeonResult<tokio::sync::oneshot::Receiver<()>> {
+    let (abort_tx, abort_rx) = tokio::sync::oneshot::channel::<()>();
+    let abort_tx = Arc::new(std::sync::Mutex::new(Some(abort_tx)));
+
+    let aborted: Handle<JsBoolean> = signal.get(cx, "aborted")?;
+    if aborted.value(cx) {
+        if let Some(tx) = abort_tx.lock().unwrap().take() {
+            let _ = tx.send(());
+        }
+    } else {
+        let add_event_listener: Handle<JsFunction> = signal.get(cx, "addEventListener")?;
+        let handler = JsFunction::new(cx, move |mut cx| {
+            if let Some(tx) = abort_tx.lock().unwrap().take() {
+                let _ = tx.send(());
+            }
+            Ok(cx.undefined())
+        })?;
+        let event_name = cx.string("abort");
+        let listener_opts = cx.empty_object();
+        let once = cx.boolean(true);
+        listener_opts.set(cx, "once", once)?;
+        add_event_listener
+            .call_with(cx)
+            .arg(event_name)
+            .arg(handler)
+            .arg(listener_opts)
+            .apply::<JsValue, _>(cx)?;
+    }
+
+    Ok(abort_rx)
+}
+
+// Resolve (or reject) the stackwalk promise with a processed dump, shared by
+// both the path and in-memory-buffer code paths above.
+fn settle_stackwalk(
+    deferred: Deferred,
+    channel: Channel,
+    res: Result<minidump_processor::ProcessState, StackwalkError>,
+    output: String,
+    pretty: bool,
+    symbol_stats: HashMap<String, SymbolStats>,
+    module_debug_info: HashMap<String, (String, String)>,
+    on_progress: Option<Arc<Root<JsFunction>>>,
+) {
+    deferred.settle_with(&channel, move |mut cx| {
+        release_progress_callback(&mut cx, on_progress);
+        match res {
+            Ok(state) => {
+                let mut buf = BufWriter::new(Vec::new());
+
+                if output == "json" {
+                    state.print_json(&mut buf, pretty).unwrap();
+                    let bytes = buf.into_inner().unwrap();
+                    let string = String::from_utf8(bytes).unwrap();
+                    let result: Handle<JsObject> =
+                        json_parse(&mut cx, &string)?.downcast_or_throw(&mut cx)?;
+                    let symbol_stats = symbol_stats_to_js(&mut cx, &symbol_stats, &module_debug_info)?;
+                    result.set(&mut cx, "symbolStats", symbol_stats)?;
+                    Ok(result.upcast())
+                } else {
+                    // Keep resolving with a bare string here, same as before
+                    // symbol stats existed — only `output: "json"` callers get
+                    // the richer object, so existing string-expecting callers of
+                    // the default human-readable mode don't break.
+                    state.print(&mut buf).unwrap();
+                    let bytes = buf.into_inner().unwrap();
+                    let string = String::from_utf8(bytes).unwrap();
+                    Ok(cx.string(string).upcast())
+                }
+            }
+            Err(StackwalkError::Aborted) => throw_typed_error(
+                &mut cx,
+                "process",
+                "Aborted",
+                "Stackwalk aborted".to_string(),
+            ),
+            Err(StackwalkError::Process(err)) => throw_typed_error(
+                &mut cx,
+                "process",
+                err.name(),
+                format!("Error processing dump: {}", err),
+            ),
+        }
+    });
+}
+
+// Build the `symbolStats` array: one entry per module the symbolizer saw,
+// combining its load outcome with the debug identifiers pulled from the
+// module list, so CI tooling and dashboards can check coverage without
+// scraping "(no symbols)" out of the text output.
+fn symbol_stats_to_js<'a, C: Context<'a>>(
+    cx: &mut C,
+    stats: &HashMap<String, SymbolStats>,
+    module_debug_info: &HashMap<String, (String, String)>,
+) -> JsResult<'a, JsArray> {
+    let array = cx.empty_array();
+    for (i, (module, stat)) in stats.iter().enumerate() {
+        let entry = cx.empty_object();
+
+        let module_name = cx.string(module);
+        entry.set(cx, "module", module_name)?;
+
+        let (debug_file, debug_id) = module_debug_info
+            .get(module)
+            .cloned()
+            .unwrap_or_default();
+        let debug_file = cx.string(debug_file);
+        entry.set(cx, "debugFile", debug_file)?;
+        let debug_id = cx.string(debug_id);
+        entry.set(cx, "debugId", debug_id)?;
+
+        let loaded_symbols = cx.boolean(stat.loaded_symbols);
+        entry.set(cx, "loadedSymbols", loaded_symbols)?;
+        let corrupt = cx.boolean(stat.corrupt_symbols);
+        entry.set(cx, "corrupt", corrupt)?;
+        let missing = cx.boolean(stat.missing_symbols);
+        entry.set(cx, "missing", missing)?;
+
+        array.set(cx, i as u32, entry)?;
+    }
+    Ok(array)
+}
+
 fn minidump_dump(mut cx: FunctionContext) -> JsResult<JsPromise> {
     let rt = runtime(&mut cx)?;
     let channel = cx.channel();
 
-    let minidump_path: Handle<JsString> = cx.argument(0)?;
-    let minidump_path = PathBuf::from(minidump_path.value(&mut cx));
+    let source_arg: Handle<JsValue> = cx.argument(0)?;
+    let source = DumpSource::from_arg(&mut cx, source_arg)?;
     let opts: Option<Handle<JsValue>> = cx.argument_opt(1);
     let opts = match opts {
         Some(o) => o.downcast_or_throw::<JsObject, FunctionContext>(&mut cx)?,
         None => cx.empty_object(),
     };
-    
+
     let brief: Option<Handle<JsBoolean>> = opts.get_opt(&mut cx, "brief")?;
     let brief = brief.map(|x| x.value(&mut cx)).unwrap_or(false);
 
@@ -142,31 +490,40 @@ fn minidump_dump(mut cx: FunctionContext) -> JsResult<JsPromise> {
     let (deferred, promise) = cx.promise();
 
     rt.spawn(async move {
-        match Minidump::read_path(minidump_path) {
-            Ok(dump) => {
-                deferred.settle_with(&channel, move |mut cx| {
-                    let mut buf = BufWriter::new(Vec::new());
-                    match print_minidump_dump(&dump, &mut buf, brief) {
-                        Ok(_) => {
-                            let bytes = buf.into_inner().unwrap();
-                            let string = String::from_utf8(bytes).unwrap();
-                            Ok(cx.string(string))
-                        },
-                        Err(err) => cx.throw_error(format!("Error processing dump: {}", err)),
-                    }
-                })
-            }
-            Err(err) => deferred.settle_with(&channel, move |mut cx| {
-                let x: NeonResult<Handle<JsValue>> =
-                    cx.throw_error(format!("{} - Error reading dump: {}", err.name(), err));
-                x
-            }),
-        };
+        with_opened_dump!(
+            source,
+            |dump| settle_dump(deferred, channel, dump, brief),
+            |err| emit_read_error(deferred, channel, err, None)
+        );
     });
 
     Ok(promise)
 }
 
+// Resolve the dump promise with the rendered text dump, shared by both the
+// path and in-memory-buffer code paths above.
+fn settle_dump<T>(deferred: Deferred, channel: Channel, dump: Minidump<'static, T>, brief: bool)
+where
+    T: Deref<Target = [u8]> + Send + 'static,
+{
+    deferred.settle_with(&channel, move |mut cx| {
+        let mut buf = BufWriter::new(Vec::new());
+        match print_minidump_dump(&dump, &mut buf, brief) {
+            Ok(_) => {
+                let bytes = buf.into_inner().unwrap();
+                let string = String::from_utf8(bytes).unwrap();
+                Ok(cx.string(string))
+            }
+            Err(err) => throw_typed_error(
+                &mut cx,
+                "process",
+                "Io",
+                format!("Error processing dump: {}", err),
+            ),
+        }
+    });
+}
+
 fn print_minidump_dump<'a, T, W>(
     dump: &Minidump<'a, T>,
     output: &mut W,
@@ -264,10 +621,180 @@ where
     Ok(())
 }
 
+// Which stream the caller asked for: a numeric stream type ID (always
+// returned as raw bytes) or a stream name (returned as a structured object
+// for the types we know how to parse, or raw bytes otherwise).
+enum StreamSelector {
+    Id(u32),
+    Name(String),
+}
+
+impl StreamSelector {
+    fn from_arg<'a, C: Context<'a>>(cx: &mut C, arg: Handle<'a, JsValue>) -> NeonResult<Self> {
+        if let Ok(name) = arg.downcast::<JsString, _>(cx) {
+            Ok(StreamSelector::Name(name.value(cx)))
+        } else if let Ok(id) = arg.downcast::<JsNumber, _>(cx) {
+            Ok(StreamSelector::Id(id.value(cx) as u32))
+        } else {
+            cx.throw_type_error("expected a stream type name or numeric stream type ID")
+        }
+    }
+}
+
+fn minidump_read_stream(mut cx: FunctionContext) -> JsResult<JsPromise> {
+    let rt = runtime(&mut cx)?;
+    let channel = cx.channel();
+
+    let source_arg: Handle<JsValue> = cx.argument(0)?;
+    let source = DumpSource::from_arg(&mut cx, source_arg)?;
+
+    let stream_type_arg: Handle<JsValue> = cx.argument(1)?;
+    let selector = StreamSelector::from_arg(&mut cx, stream_type_arg)?;
+
+    // Create a JavaScript promise and a `deferred` handle for resolving it.
+    // It is important to be careful not to perform failable actions after
+    // creating the promise to avoid an unhandled rejection.
+    let (deferred, promise) = cx.promise();
+
+    rt.spawn(async move {
+        with_opened_dump!(
+            source,
+            |dump| settle_read_stream(deferred, channel, dump, selector),
+            |err| emit_read_error(deferred, channel, err, None)
+        );
+    });
+
+    Ok(promise)
+}
+
+// Resolve the read-stream promise, either with the stream's raw bytes as a
+// `Buffer` or, for the typed streams we know how to parse, a structured
+// object built by round-tripping the stream's serde representation through
+// `JSON.parse` (same trick as the `output: "json"` stackwalk result).
+fn settle_read_stream<T>(
+    deferred: Deferred,
+    channel: Channel,
+    dump: Minidump<'static, T>,
+    selector: StreamSelector,
+) where
+    T: Deref<Target = [u8]> + Send + 'static,
+{
+    use minidump_common::format::MINIDUMP_STREAM_TYPE;
+
+    deferred.settle_with(&channel, move |mut cx| match selector {
+        StreamSelector::Id(id) => raw_stream_to_buffer(&mut cx, &dump, id),
+        StreamSelector::Name(name) => match name.as_str() {
+            "MinidumpSystemInfo" => {
+                typed_stream_to_js(&mut cx, dump.get_stream::<MinidumpSystemInfo>())
+            }
+            "MinidumpThreadList" => {
+                typed_stream_to_js(&mut cx, dump.get_stream::<MinidumpThreadList<'_>>())
+            }
+            "MinidumpMemoryInfoList" => {
+                typed_stream_to_js(&mut cx, dump.get_stream::<MinidumpMemoryInfoList<'_>>())
+            }
+            "MinidumpCrashpadInfo" => {
+                typed_stream_to_js(&mut cx, dump.get_stream::<MinidumpCrashpadInfo>())
+            }
+            "LinuxCmdLine" => {
+                linux_stream_to_js(&mut cx, &dump, MINIDUMP_STREAM_TYPE::LinuxCmdLine)
+            }
+            "LinuxEnviron" => {
+                linux_stream_to_js(&mut cx, &dump, MINIDUMP_STREAM_TYPE::LinuxEnviron)
+            }
+            "LinuxLsbRelease" => {
+                linux_stream_to_js(&mut cx, &dump, MINIDUMP_STREAM_TYPE::LinuxLsbRelease)
+            }
+            "LinuxProcStatus" => {
+                linux_stream_to_js(&mut cx, &dump, MINIDUMP_STREAM_TYPE::LinuxProcStatus)
+            }
+            "LinuxCpuInfo" => {
+                linux_stream_to_js(&mut cx, &dump, MINIDUMP_STREAM_TYPE::LinuxCpuInfo)
+            }
+            "LinuxMaps" => linux_stream_to_js(&mut cx, &dump, MINIDUMP_STREAM_TYPE::LinuxMaps),
+            // Other recognized stream names fall back to their raw bytes —
+            // only the four `Minidump*` types above get a structured object;
+            // the Linux streams above are returned as raw bytes too.
+            other => match stream_type_id_for_name(other) {
+                Some(id) => raw_stream_to_buffer(&mut cx, &dump, id),
+                None => cx.throw_type_error(format!("unknown stream type: {}", other)),
+            },
+        },
+    });
+}
+
+// Resolve a known (but not specially parsed above) stream name to its
+// `MINIDUMP_STREAM_TYPE` ID, so `minidumpReadStream` can still return its
+// raw bytes instead of erroring.
+fn stream_type_id_for_name(name: &str) -> Option<u32> {
+    use minidump_common::format::MINIDUMP_STREAM_TYPE::*;
+    let stream_type = match name {
+        "MinidumpModuleList" => ModuleListStream,
+        "MinidumpUnloadedModuleList" => UnloadedModuleListStream,
+        "MinidumpMemoryList" => MemoryListStream,
+        "MinidumpMemory64List" => Memory64ListStream,
+        "MinidumpException" => ExceptionStream,
+        "MinidumpAssertion" => AssertionInfoStream,
+        "MinidumpMiscInfo" => MiscInfoStream,
+        "MinidumpBreakpadInfo" => BreakpadInfoStream,
+        "MinidumpThreadNames" => ThreadNamesStream,
+        _ => return None,
+    };
+    Some(stream_type as u32)
+}
+
+fn raw_stream_to_buffer<'a, C: Context<'a>, T>(
+    cx: &mut C,
+    dump: &Minidump<'a, T>,
+    id: u32,
+) -> JsResult<'a, JsValue>
+where
+    T: Deref<Target = [u8]> + 'a,
+{
+    match dump.get_raw_stream(id) {
+        Ok(bytes) => Ok(JsBuffer::from_slice(cx, bytes)?.upcast()),
+        Err(err) => throw_typed_error(
+            cx,
+            "process",
+            err.name(),
+            format!("Error reading stream: {}", err),
+        ),
+    }
+}
+
+fn linux_stream_to_js<'a, C: Context<'a>, T>(
+    cx: &mut C,
+    dump: &Minidump<'a, T>,
+    stream_type: minidump_common::format::MINIDUMP_STREAM_TYPE,
+) -> JsResult<'a, JsValue>
+where
+    T: Deref<Target = [u8]> + 'a,
+{
+    raw_stream_to_buffer(cx, dump, stream_type as u32)
+}
+
+fn typed_stream_to_js<'a, C: Context<'a>, S: serde::Serialize>(
+    cx: &mut C,
+    stream: Result<S, Error>,
+) -> JsResult<'a, JsValue> {
+    match stream {
+        Ok(stream) => {
+            let json = serde_json::to_string(&stream).unwrap();
+            json_parse(cx, &json)
+        }
+        Err(err) => throw_typed_error(
+            cx,
+            "process",
+            err.name(),
+            format!("Error reading stream: {}", err),
+        ),
+    }
+}
 
 #[neon::main]
 fn main(mut cx: ModuleContext) -> NeonResult<()> {
     cx.export_function("minidumpStackwalk", minidump_stackwalk)?;
     cx.export_function("minidumpDump", minidump_dump)?;
+    cx.export_function("minidumpReadStream", minidump_read_stream)?;
     Ok(())
 }